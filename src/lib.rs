@@ -0,0 +1,13 @@
+//! Core of the Trusty Rusty Todo List, usable as a library.
+//!
+//! ```
+//! use trusty_rusty_todo_list::models::{Category, Todo};
+//!
+//! let mut home = Category { id: 1, name: "Home".to_string(), todos: Vec::new() };
+//! home.todos.push(Todo { id: 1, title: "Water plants".to_string(), completed: false });
+//!
+//! assert_eq!(home.todos[0].title, "Water plants");
+//! ```
+
+pub mod cli;
+pub mod models;
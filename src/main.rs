@@ -1,8 +1,3 @@
-mod cli;
-mod models;
-
-use models::{Todo, Category};
-
 fn main() {
     // Parse the CLI arguments
     // Handle the operations